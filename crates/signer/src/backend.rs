@@ -0,0 +1,71 @@
+use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
+use cb_common::commit::request::SignedProxyDelegation;
+
+use crate::{error::SignerModuleError, manager::SigningManager};
+
+/// The request-handling surface a signing endpoint needs, independent of
+/// where the keys actually live. `SigningManager` implements this directly
+/// for in-process keys; `RemoteSigningManager` implements it by forwarding
+/// to another node over HTTP, so a relayer node can expose the same API
+/// without holding any keys itself.
+#[async_trait::async_trait]
+pub trait SigningBackend: Send + Sync {
+    async fn sign_consensus(
+        &self,
+        pubkey: &BlsPublicKey,
+        object_root: &[u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError>;
+
+    async fn sign_proxy(
+        &self,
+        pubkey: &[u8],
+        object_root: &[u8; 32],
+    ) -> Result<Vec<u8>, SignerModuleError>;
+
+    async fn get_delegation(
+        &self,
+        pubkey: &[u8],
+    ) -> Result<SignedProxyDelegation, SignerModuleError>;
+
+    /// Whether this backend holds (or, for a remote backend, has access to)
+    /// the consensus key for `pubkey`. Async because a backend that forwards
+    /// over HTTP has to ask the node it talks to rather than answer from
+    /// local state.
+    async fn has_consensus(&self, pubkey: &BlsPublicKey) -> bool;
+
+    async fn has_proxy(&self, pubkey: &[u8]) -> bool;
+}
+
+#[async_trait::async_trait]
+impl SigningBackend for SigningManager {
+    async fn sign_consensus(
+        &self,
+        pubkey: &BlsPublicKey,
+        object_root: &[u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError> {
+        SigningManager::sign_consensus(self, pubkey, object_root).await
+    }
+
+    async fn sign_proxy(
+        &self,
+        pubkey: &[u8],
+        object_root: &[u8; 32],
+    ) -> Result<Vec<u8>, SignerModuleError> {
+        SigningManager::sign_proxy(self, pubkey, object_root).await
+    }
+
+    async fn get_delegation(
+        &self,
+        pubkey: &[u8],
+    ) -> Result<SignedProxyDelegation, SignerModuleError> {
+        SigningManager::get_delegation(self, pubkey)
+    }
+
+    async fn has_consensus(&self, pubkey: &BlsPublicKey) -> bool {
+        SigningManager::has_consensus(self, pubkey)
+    }
+
+    async fn has_proxy(&self, pubkey: &[u8]) -> bool {
+        SigningManager::has_proxy(self, pubkey)
+    }
+}
@@ -0,0 +1,95 @@
+use std::net::SocketAddr;
+
+use alloy::rpc::types::beacon::BlsPublicKey;
+use cb_common::{config::LogsSettings, signer::BlsSecretKey};
+use serde::Deserialize;
+use url::Url;
+
+use crate::threshold::ThresholdShare;
+
+/// Startup config for a relayer node
+/// ([`crate::service::SigningService::run_relayer`]): unlike a key-custody
+/// node, a relayer holds no keys itself and just needs to know which backend
+/// nodes to forward requests to.
+#[derive(Clone, Deserialize)]
+pub struct StartRelayerConfig {
+    pub backend_urls: Vec<Url>,
+    pub server_address: SocketAddr,
+    pub logs_settings: LogsSettings,
+}
+
+impl StartRelayerConfig {
+    /// Load from the `CB_SIGNER_RELAYER_CONFIG` env var, a JSON blob of the
+    /// same shape as this struct. Mirrors
+    /// `cb_common::config::StartSignerConfig::load_from_env`.
+    pub fn load_from_env() -> eyre::Result<Self> {
+        let raw = std::env::var("CB_SIGNER_RELAYER_CONFIG")
+            .map_err(|err| eyre::eyre!("missing CB_SIGNER_RELAYER_CONFIG: {err}"))?;
+        serde_json::from_str(&raw)
+            .map_err(|err| eyre::eyre!("invalid CB_SIGNER_RELAYER_CONFIG: {err}"))
+    }
+}
+
+/// Registers a consensus key that's signed for remotely by the
+/// `/api/v1/commit-boost/sign` daemon [`crate::remote::RemoteSigner`] talks
+/// to, for [`crate::service::SigningService::run`] to wire into the manager
+/// at startup via
+/// [`crate::manager::SigningManager::add_remote_consensus_signer`].
+#[derive(Clone, Deserialize)]
+pub struct RemoteConsensusSignerConfig {
+    pub pubkey: BlsPublicKey,
+    pub url: Url,
+}
+
+/// Registers a consensus key that's signed for via a Shamir-shared secret:
+/// this node holds `share_secret`/`share_index` and gathers enough partial
+/// signatures from `peers` to combine one, via
+/// [`crate::manager::SigningManager::add_threshold_consensus_signer`]. The
+/// share itself is provisioned out of band (e.g. with
+/// [`crate::threshold::split_secret`]); this config just says where this
+/// node's piece fits into the scheme. `share_secret` is raw key bytes rather
+/// than a [`ThresholdShare`] directly since `BlsSecretKey` doesn't implement
+/// `Deserialize` (mirrors how `ProxyPayload` stores proxy secrets in
+/// `keystore.rs`).
+#[derive(Clone, Deserialize)]
+pub struct ThresholdConsensusSignerConfig {
+    pub pubkey: BlsPublicKey,
+    pub share_index: u64,
+    pub share_secret: Vec<u8>,
+    pub threshold: usize,
+    pub peers: Vec<Url>,
+}
+
+impl ThresholdConsensusSignerConfig {
+    pub(crate) fn share(&self) -> eyre::Result<ThresholdShare> {
+        let secret = BlsSecretKey::from_bytes(&self.share_secret)
+            .map_err(|err| eyre::eyre!("invalid threshold share secret: {err:?}"))?;
+        Ok(ThresholdShare { index: self.share_index, secret })
+    }
+}
+
+/// Consensus signers a key-custody node doesn't hold the secret for
+/// directly. Loaded independently of `cb_common::config::StartSignerConfig`,
+/// which only covers this node's own keystore, and merged in by
+/// [`crate::service::SigningService::run`].
+#[derive(Clone, Default, Deserialize)]
+pub struct ExternalConsensusSignersConfig {
+    #[serde(default)]
+    pub remote: Vec<RemoteConsensusSignerConfig>,
+    #[serde(default)]
+    pub threshold: Vec<ThresholdConsensusSignerConfig>,
+}
+
+impl ExternalConsensusSignersConfig {
+    /// Load from the `CB_SIGNER_EXTERNAL_CONSENSUS_SIGNERS` env var, a JSON
+    /// blob of the same shape as this struct. Absent means no external
+    /// consensus signers are configured, which is the common case.
+    pub fn load_from_env() -> eyre::Result<Self> {
+        match std::env::var("CB_SIGNER_EXTERNAL_CONSENSUS_SIGNERS") {
+            Ok(raw) => serde_json::from_str(&raw)
+                .map_err(|err| eyre::eyre!("invalid CB_SIGNER_EXTERNAL_CONSENSUS_SIGNERS: {err}")),
+            Err(std::env::VarError::NotPresent) => Ok(Self::default()),
+            Err(err) => Err(eyre::eyre!(err)),
+        }
+    }
+}
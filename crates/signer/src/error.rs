@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SignerModuleError {
+    #[error("unknown consensus signer: {0:?}")]
+    UnknownConsensusSigner(Vec<u8>),
+
+    #[error("unknown proxy signer: {0:?}")]
+    UnknownProxySigner(Vec<u8>),
+
+    #[error("remote signer request failed: {0}")]
+    RemoteSigner(String),
+
+    #[error("failed to aggregate BLS signatures: {0}")]
+    Aggregation(String),
+
+    #[error("proxy keystore error: {0}")]
+    Keystore(String),
+
+    #[error("threshold signer error: {0}")]
+    ThresholdSigner(String),
+
+    #[error("relay request failed: {0}")]
+    Relay(String),
+}
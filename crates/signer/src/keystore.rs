@@ -0,0 +1,257 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use cb_common::{
+    commit::request::SignedProxyDelegation,
+    signer::{BlsSecretKey, EcdsaSecretKey, GenericProxySigner, ProxySigner, SecretKey, Signer},
+    types::ModuleId,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use xsalsa20poly1305::{
+    aead::{Aead, KeyInit},
+    Key, Nonce, XSalsa20Poly1305,
+};
+
+use crate::error::SignerModuleError;
+
+const SALT_FILE: &str = "keystore.salt";
+
+/// Where to find the persisted proxy keystore and how to unlock it.
+pub struct KeystoreConfig {
+    pub dir: PathBuf,
+    pub passphrase: String,
+}
+
+/// Ciphertext form of a single proxy entry on disk.
+#[derive(Serialize, Deserialize)]
+struct SealedProxyEntry {
+    nonce: [u8; 24],
+    ciphertext: Vec<u8>,
+}
+
+/// Plaintext payload sealed inside a [`SealedProxyEntry`]. Never written to
+/// disk unencrypted.
+#[derive(Serialize, Deserialize)]
+enum ProxyPayload {
+    Bls { secret: Vec<u8>, delegation: SignedProxyDelegation, module_id: ModuleId },
+    Ecdsa { secret: Vec<u8>, delegation: SignedProxyDelegation, module_id: ModuleId },
+}
+
+/// Encrypted, on-disk store of generated proxy keys, so they survive signer
+/// restarts without modules having to re-delegate.
+///
+/// Each entry is a file named after the proxy's pubkey. The encryption key is
+/// derived from an operator-supplied passphrase with Argon2id, and every
+/// entry is sealed with XSalsa20-Poly1305 (NaCl "secretbox"): a random
+/// 24-byte nonce plus a Poly1305 MAC that's checked on load, so a wrong
+/// passphrase or tampered file is rejected instead of silently misread.
+pub struct ProxyKeystore {
+    dir: PathBuf,
+    cipher: XSalsa20Poly1305,
+}
+
+impl ProxyKeystore {
+    pub fn open(dir: impl Into<PathBuf>, passphrase: &str) -> Result<Self, SignerModuleError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+        let salt = Self::load_or_create_salt(&dir)?;
+
+        let mut key_bytes = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), &salt, &mut key_bytes)
+            .map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+        Ok(Self { dir, cipher: XSalsa20Poly1305::new(Key::from_slice(&key_bytes)) })
+    }
+
+    fn load_or_create_salt(dir: &Path) -> Result<Vec<u8>, SignerModuleError> {
+        let salt_path = dir.join(SALT_FILE);
+        if salt_path.exists() {
+            return fs::read(&salt_path).map_err(|err| SignerModuleError::Keystore(err.to_string()));
+        }
+
+        let mut salt = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        fs::write(&salt_path, salt).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+        Ok(salt.to_vec())
+    }
+
+    fn entry_path(&self, pubkey: &[u8]) -> PathBuf {
+        self.dir.join(hex::encode(pubkey))
+    }
+
+    /// Persist a BLS proxy signer. Writes to a temp file and renames it into
+    /// place so a crash mid-write can't corrupt an existing entry.
+    pub fn store_bls(
+        &self,
+        module_id: &ModuleId,
+        proxy: &ProxySigner<BlsSecretKey>,
+    ) -> Result<(), SignerModuleError> {
+        let payload = ProxyPayload::Bls {
+            secret: proxy.signer().secret_bytes(),
+            delegation: proxy.delegation(),
+            module_id: module_id.clone(),
+        };
+        self.write_entry(proxy.pubkey().as_ref(), &payload)
+    }
+
+    /// Persist an ECDSA proxy signer. See [`Self::store_bls`].
+    pub fn store_ecdsa(
+        &self,
+        module_id: &ModuleId,
+        proxy: &ProxySigner<EcdsaSecretKey>,
+    ) -> Result<(), SignerModuleError> {
+        let payload = ProxyPayload::Ecdsa {
+            secret: proxy.signer().secret_bytes(),
+            delegation: proxy.delegation(),
+            module_id: module_id.clone(),
+        };
+        self.write_entry(proxy.pubkey().as_ref(), &payload)
+    }
+
+    fn write_entry(&self, pubkey: &[u8], payload: &ProxyPayload) -> Result<(), SignerModuleError> {
+        let mut nonce = [0u8; 24];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let plaintext =
+            bincode::serialize(payload).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_ref())
+            .map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+        let sealed = SealedProxyEntry { nonce, ciphertext };
+        let bytes =
+            bincode::serialize(&sealed).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+        let final_path = self.entry_path(pubkey);
+        let tmp_path = final_path.with_extension("tmp");
+        fs::write(&tmp_path, bytes).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+        fs::rename(&tmp_path, &final_path).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Decrypt and return every proxy signer persisted in this keystore, for
+    /// re-registration with a [`crate::manager::SigningManager`] on startup.
+    pub fn load_all(&self) -> Result<Vec<(ModuleId, GenericProxySigner)>, SignerModuleError> {
+        let mut loaded = Vec::new();
+
+        for entry in
+            fs::read_dir(&self.dir).map_err(|err| SignerModuleError::Keystore(err.to_string()))?
+        {
+            let path = entry.map_err(|err| SignerModuleError::Keystore(err.to_string()))?.path();
+            let is_entry_file = path.is_file()
+                && path.file_name().is_some_and(|name| name != SALT_FILE)
+                && path.extension().is_none_or(|ext| ext != "tmp");
+            if !is_entry_file {
+                continue;
+            }
+
+            let bytes = fs::read(&path).map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+            let sealed: SealedProxyEntry = bincode::deserialize(&bytes)
+                .map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+            let plaintext = self
+                .cipher
+                .decrypt(Nonce::from_slice(&sealed.nonce), sealed.ciphertext.as_ref())
+                .map_err(|_| {
+                    SignerModuleError::Keystore(
+                        "failed to decrypt proxy entry (wrong passphrase or tampered data)"
+                            .to_string(),
+                    )
+                })?;
+            let payload: ProxyPayload = bincode::deserialize(&plaintext)
+                .map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+
+            loaded.push(match payload {
+                ProxyPayload::Bls { secret, delegation, module_id } => {
+                    let signer = Signer::<BlsSecretKey>::from_bytes(&secret)
+                        .map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+                    (module_id, GenericProxySigner::Bls(ProxySigner::new(signer, delegation)))
+                }
+                ProxyPayload::Ecdsa { secret, delegation, module_id } => {
+                    let signer = Signer::<EcdsaSecretKey>::from_bytes(&secret)
+                        .map_err(|err| SignerModuleError::Keystore(err.to_string()))?;
+                    (module_id, GenericProxySigner::Ecdsa(ProxySigner::new(signer, delegation)))
+                }
+            });
+        }
+
+        Ok(loaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cb_common::{commit::request::ProxyDelegation, types::Chain};
+    use tree_hash::TreeHash;
+
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let mut suffix = [0u8; 8];
+        rand::thread_rng().fill_bytes(&mut suffix);
+        std::env::temp_dir().join(format!("cb-signer-keystore-test-{}", hex::encode(suffix)))
+    }
+
+    async fn signed_bls_proxy() -> ProxySigner<BlsSecretKey> {
+        let consensus_signer = Signer::<BlsSecretKey>::new_random();
+        let proxy_signer = Signer::<BlsSecretKey>::new_random();
+
+        let message =
+            ProxyDelegation { delegator: consensus_signer.pubkey(), proxy: proxy_signer.pubkey().into() };
+        let signature = consensus_signer.sign(Chain::Holesky, message.tree_hash_root().0).await;
+
+        ProxySigner::new(proxy_signer, SignedProxyDelegation { signature, message })
+    }
+
+    #[tokio::test]
+    async fn test_store_and_load_bls_proxy_roundtrip() {
+        let dir = temp_dir();
+        let module_id = ModuleId("TEST_MODULE".to_string());
+        let proxy = signed_bls_proxy().await;
+        let proxy_pubkey = proxy.pubkey();
+
+        ProxyKeystore::open(&dir, "correct horse battery staple")
+            .unwrap()
+            .store_bls(&module_id, &proxy)
+            .unwrap();
+
+        let loaded = ProxyKeystore::open(&dir, "correct horse battery staple").unwrap().load_all().unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(loaded.len(), 1, "store_bls entry must round-trip through load_all");
+        let (loaded_module_id, loaded_proxy) = &loaded[0];
+        assert_eq!(loaded_module_id.0, module_id.0);
+
+        match loaded_proxy {
+            GenericProxySigner::Bls(loaded_proxy) => {
+                assert_eq!(loaded_proxy.pubkey().as_ref(), proxy_pubkey.as_ref());
+            }
+            GenericProxySigner::Ecdsa(_) => panic!("expected a BLS proxy signer"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_all_rejects_wrong_passphrase() {
+        let dir = temp_dir();
+        let module_id = ModuleId("TEST_MODULE".to_string());
+        let proxy = signed_bls_proxy().await;
+
+        ProxyKeystore::open(&dir, "correct horse battery staple")
+            .unwrap()
+            .store_bls(&module_id, &proxy)
+            .unwrap();
+
+        let result = ProxyKeystore::open(&dir, "wrong passphrase").unwrap().load_all();
+        fs::remove_dir_all(&dir).ok();
+
+        assert!(result.is_err(), "wrong passphrase must fail to decrypt, not silently misread");
+    }
+}
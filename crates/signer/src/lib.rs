@@ -0,0 +1,10 @@
+pub mod backend;
+pub mod config;
+pub mod error;
+pub mod keystore;
+pub mod manager;
+pub mod relayer;
+pub mod remote;
+pub mod remote_manager;
+pub mod service;
+pub mod threshold;
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
-use blst::min_pk::SecretKey as BlsSecretKey;
+use blst::min_pk::{AggregateSignature, SecretKey as BlsSecretKey, Signature as BlsSigPoint};
 use cb_common::{
     commit::request::{ProxyDelegation, SignedProxyDelegation},
     signer::{
@@ -10,8 +10,14 @@ use cb_common::{
     types::{Chain, ModuleId},
 };
 use tree_hash::TreeHash;
+use url::Url;
 
-use crate::error::SignerModuleError;
+use crate::{
+    error::SignerModuleError,
+    keystore::{KeystoreConfig, ProxyKeystore},
+    remote::RemoteSigner,
+    threshold::{PartialSignResponse, ThresholdShare, ThresholdSigner},
+};
 
 #[derive(Default)]
 struct ProxySigners {
@@ -80,29 +86,113 @@ impl GetProxySigner<EcdsaSecretKey> for ProxySigners {
     }
 }
 
+/// Result of [`SigningManager::sign_proxy_batch`]: BLS proxy signatures are
+/// collapsed into a single aggregate, ECDSA ones can't be and are returned
+/// per-signer.
+pub struct ProxyBatchSignature {
+    pub bls_aggregate: Option<(BlsSignature, Vec<PubKey<BlsSecretKey>>)>,
+    pub ecdsa: Vec<(PubKey<EcdsaSecretKey>, Vec<u8>)>,
+}
+
+/// Where a consensus key's secret actually lives. `Local` keeps the keypair
+/// in-process, `Remote` defers to an external daemon over the root-signing
+/// API of [`RemoteSigner`] and only ever sees the pubkey. That API is not the
+/// standard Web3Signer `/api/v1/eth2/sign` contract, so an unmodified
+/// Web3Signer cannot serve as the remote daemon.
+enum ConsensusSignerBackend {
+    Local(Signer),
+    Remote(RemoteSigner),
+    Threshold(ThresholdSigner),
+}
+
+impl ConsensusSignerBackend {
+    async fn sign(
+        &self,
+        chain: Chain,
+        object_root: [u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError> {
+        match self {
+            ConsensusSignerBackend::Local(signer) => Ok(signer.sign(chain, object_root).await),
+            ConsensusSignerBackend::Remote(remote) => remote.sign(chain, object_root).await,
+            ConsensusSignerBackend::Threshold(threshold) => {
+                threshold.sign(chain, object_root).await
+            }
+        }
+    }
+}
+
 pub struct SigningManager {
     chain: Chain,
-    consensus_signers: HashMap<BlsPublicKey, Signer>,
+    consensus_signers: HashMap<BlsPublicKey, ConsensusSignerBackend>,
     proxy_signers: ProxySigners, // HashMap<Vec<u8>, ProxySigner>,
     // proxy_delegations:
     /// Map of module ids to their associated proxy pubkeys.
     /// Used to retrieve the corresponding proxy signer from the signing
     /// manager.
     proxy_pubkeys: HashMap<ModuleId, Vec<GenericPubkey>>,
+    /// Encrypted on-disk store that `create_proxy` persists new proxies to,
+    /// if the manager was started with one.
+    keystore: Option<ProxyKeystore>,
 }
 
 impl SigningManager {
-    pub fn new(chain: Chain) -> Self {
-        Self {
+    /// Build a new manager for `chain`. If `keystore` is set, its directory
+    /// is decrypted with the configured passphrase and every proxy signer
+    /// found there is re-registered, so generated proxies survive restarts.
+    pub fn new(chain: Chain, keystore: Option<KeystoreConfig>) -> Result<Self, SignerModuleError> {
+        let keystore = keystore.map(|cfg| ProxyKeystore::open(cfg.dir, &cfg.passphrase)).transpose()?;
+
+        let mut proxy_signers = ProxySigners::default();
+        let mut proxy_pubkeys: HashMap<ModuleId, Vec<GenericPubkey>> = HashMap::new();
+
+        if let Some(keystore) = &keystore {
+            for (module_id, proxy) in keystore.load_all()? {
+                let pubkey = match &proxy {
+                    GenericProxySigner::Bls(p) => GenericPubkey::from(p.pubkey()),
+                    GenericProxySigner::Ecdsa(p) => GenericPubkey::from(p.pubkey()),
+                };
+                proxy_signers.add(proxy);
+                proxy_pubkeys.entry(module_id).or_default().push(pubkey);
+            }
+        }
+
+        Ok(Self {
             chain,
             consensus_signers: Default::default(),
-            proxy_signers: Default::default(),
-            proxy_pubkeys: Default::default(),
-        }
+            proxy_signers,
+            proxy_pubkeys,
+            keystore,
+        })
     }
 
     pub fn add_consensus_signer(&mut self, signer: Signer) {
-        self.consensus_signers.insert(signer.pubkey(), signer);
+        self.consensus_signers.insert(signer.pubkey(), ConsensusSignerBackend::Local(signer));
+    }
+
+    /// Register a consensus key that is signed for remotely by a daemon
+    /// reachable at `url` that serves `/api/v1/commit-boost/sign/{pubkey}`
+    /// (see [`RemoteSigner`]); the standard Web3Signer sign API is not
+    /// supported. Commit-Boost never sees the secret key for this pubkey.
+    pub fn add_remote_consensus_signer(&mut self, pubkey: BlsPublicKey, url: Url) {
+        self.consensus_signers
+            .insert(pubkey, ConsensusSignerBackend::Remote(RemoteSigner::new(pubkey, url)));
+    }
+
+    /// Register a consensus key that is only ever held as a Shamir share:
+    /// `sign_consensus` will gather partial signatures from `peers` and
+    /// combine them itself, so no single node (including this one) ever
+    /// reconstructs the full secret.
+    pub fn add_threshold_consensus_signer(
+        &mut self,
+        pubkey: BlsPublicKey,
+        share: ThresholdShare,
+        threshold: usize,
+        peers: Vec<Url>,
+    ) {
+        self.consensus_signers.insert(
+            pubkey,
+            ConsensusSignerBackend::Threshold(ThresholdSigner::new(pubkey, share, threshold, peers)),
+        );
     }
 
     pub fn add_proxy_signer(&mut self, proxy: GenericProxySigner) {
@@ -124,7 +214,14 @@ impl SigningManager {
         let message = ProxyDelegation { delegator, proxy: proxy_pubkey };
         let signature = self.sign_consensus(&delegator, &message.tree_hash_root().0).await?;
         let signed_delegation: SignedProxyDelegation = SignedProxyDelegation { signature, message };
-        let proxy_signer = ProxySigner::new(signer, signed_delegation).into();
+        let proxy_signer: GenericProxySigner = ProxySigner::new(signer, signed_delegation).into();
+
+        if let Some(keystore) = &self.keystore {
+            match &proxy_signer {
+                GenericProxySigner::Bls(proxy) => keystore.store_bls(&module_id, proxy)?,
+                GenericProxySigner::Ecdsa(proxy) => keystore.store_ecdsa(&module_id, proxy)?,
+            }
+        }
 
         // Add the new proxy key to the manager's internal state
         self.add_proxy_signer(proxy_signer);
@@ -133,8 +230,6 @@ impl SigningManager {
         Ok(signed_delegation)
     }
 
-    // TODO: double check what we can actually sign here with different providers eg
-    // web3 signer
     pub async fn sign_consensus(
         &self,
         pubkey: &BlsPublicKey,
@@ -144,9 +239,30 @@ impl SigningManager {
             .consensus_signers
             .get(pubkey)
             .ok_or(SignerModuleError::UnknownConsensusSigner(pubkey.to_vec()))?;
-        let signature = signer.sign(self.chain, *object_root).await;
 
-        Ok(signature)
+        signer.sign(self.chain, *object_root).await
+    }
+
+    /// Produce this node's partial signature over `object_root` for a
+    /// threshold-shared consensus key, for a peer collecting shares via
+    /// `ThresholdSigner::request_partial` to combine. Errors if `pubkey`
+    /// isn't registered here at all, or is registered but not as a
+    /// threshold share.
+    pub async fn sign_threshold_partial(
+        &self,
+        pubkey: &BlsPublicKey,
+        object_root: &[u8; 32],
+    ) -> Result<PartialSignResponse, SignerModuleError> {
+        match self
+            .consensus_signers
+            .get(pubkey)
+            .ok_or(SignerModuleError::UnknownConsensusSigner(pubkey.to_vec()))?
+        {
+            ConsensusSignerBackend::Threshold(threshold) => {
+                threshold.sign_partial_response(self.chain, *object_root).await
+            }
+            _ => Err(SignerModuleError::UnknownConsensusSigner(pubkey.to_vec())),
+        }
     }
 
     fn find_proxy(&self, pubkey: &[u8]) -> Option<GenericProxySigner> {
@@ -171,6 +287,52 @@ impl SigningManager {
         Ok(signature)
     }
 
+    /// Sign many `(pubkey, object_root)` pairs at once. BLS proxy signatures
+    /// are collapsed into a single aggregated `BlsSignature` (one pairing
+    /// check verifies the whole batch via `aggregate_verify`), since BLS
+    /// signature points can simply be summed. ECDSA signatures cannot be
+    /// aggregated this way, so they are returned individually.
+    pub async fn sign_proxy_batch(
+        &self,
+        requests: &[(Vec<u8>, [u8; 32])],
+    ) -> Result<ProxyBatchSignature, SignerModuleError> {
+        let mut bls_sigs = Vec::new();
+        let mut ecdsa_sigs = Vec::new();
+
+        for (pubkey, object_root) in requests {
+            let proxy = self
+                .find_proxy(pubkey)
+                .ok_or(SignerModuleError::UnknownProxySigner(pubkey.clone()))?;
+
+            match proxy {
+                GenericProxySigner::Bls(proxy) => {
+                    let sig_bytes = proxy.sign(self.chain, *object_root).await;
+                    let sig_point = BlsSigPoint::from_bytes(&sig_bytes)
+                        .map_err(|err| SignerModuleError::Aggregation(format!("{err:?}")))?;
+                    bls_sigs.push((proxy.pubkey(), sig_point));
+                }
+                GenericProxySigner::Ecdsa(proxy) => {
+                    let sig_bytes = proxy.sign(self.chain, *object_root).await;
+                    ecdsa_sigs.push((proxy.pubkey(), sig_bytes));
+                }
+            }
+        }
+
+        let bls_aggregate = if bls_sigs.is_empty() {
+            None
+        } else {
+            let points: Vec<&BlsSigPoint> = bls_sigs.iter().map(|(_, sig)| sig).collect();
+            let aggregate = AggregateSignature::aggregate(&points, true)
+                .map_err(|err| SignerModuleError::Aggregation(format!("{err:?}")))?;
+            let signature = BlsSignature::from_slice(&aggregate.to_signature().to_bytes());
+            let signers = bls_sigs.into_iter().map(|(pubkey, _)| pubkey).collect();
+
+            Some((signature, signers))
+        };
+
+        Ok(ProxyBatchSignature { bls_aggregate, ecdsa: ecdsa_sigs })
+    }
+
     pub fn consensus_pubkeys(&self) -> Vec<BlsPublicKey> {
         self.consensus_signers.keys().cloned().collect()
     }
@@ -213,7 +375,7 @@ mod tests {
     }
 
     fn init_signing_manager() -> (SigningManager, BlsPublicKey) {
-        let mut signing_manager = SigningManager::new(*CHAIN);
+        let mut signing_manager = SigningManager::new(*CHAIN, None).unwrap();
 
         let consensus_signer = Signer::new_random();
         let consensus_pk = consensus_signer.pubkey();
@@ -296,4 +458,52 @@ mod tests {
             "Proxy keypair must produce valid signatures of messages."
         )
     }
+
+    #[tokio::test]
+    async fn test_sign_proxy_batch_aggregate_verifies() {
+        use blst::{min_pk::PublicKey as BlsPubkeyPoint, BLST_ERROR};
+
+        use crate::threshold::BLS_DST;
+
+        let (mut signing_manager, consensus_pk) = init_signing_manager();
+
+        let delegation_a = signing_manager
+            .create_proxy::<BlsSecretKey>(MODULE_ID.clone(), consensus_pk.clone())
+            .await
+            .unwrap();
+        let delegation_b = signing_manager
+            .create_proxy::<BlsSecretKey>(MODULE_ID.clone(), consensus_pk.clone())
+            .await
+            .unwrap();
+
+        let data_root = Hash256::random();
+        let object_root = *data_root.as_fixed_bytes();
+
+        let requests = vec![
+            (delegation_a.message.proxy.as_ref().to_vec(), object_root),
+            (delegation_b.message.proxy.as_ref().to_vec(), object_root),
+        ];
+
+        let batch = signing_manager.sign_proxy_batch(&requests).await.unwrap();
+        let (aggregate, signers) = batch.bls_aggregate.expect("both proxies are BLS");
+
+        assert_eq!(signers.len(), 2, "batch must report both aggregated signers");
+
+        let domain = CHAIN.builder_domain();
+        let signing_root = compute_signing_root(object_root.tree_hash_root().0, domain);
+        let msgs: Vec<&[u8]> = vec![signing_root.as_ref(), signing_root.as_ref()];
+
+        let pubkey_points: Vec<BlsPubkeyPoint> =
+            signers.iter().map(|pk| BlsPubkeyPoint::from_bytes(pk.as_ref()).unwrap()).collect();
+        let pubkey_refs: Vec<&BlsPubkeyPoint> = pubkey_points.iter().collect();
+
+        let sig_point = BlsSigPoint::from_bytes(aggregate.as_ref()).unwrap();
+        let result = sig_point.aggregate_verify(true, &msgs, BLS_DST, &pubkey_refs, true);
+
+        assert_eq!(
+            result,
+            BLST_ERROR::BLST_SUCCESS,
+            "aggregate signature from sign_proxy_batch must verify against both proxy pubkeys"
+        );
+    }
 }
@@ -0,0 +1,96 @@
+use std::{collections::HashMap, sync::Arc};
+
+use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
+use cb_common::commit::request::SignedProxyDelegation;
+
+use crate::{backend::SigningBackend, error::SignerModuleError};
+
+/// A non-signing node that exposes the same sign/delegation API surface as
+/// a `SigningManager` but holds no keys: it looks up which backend owns the
+/// requested pubkey and proxies the request. This separates the
+/// public-facing endpoint from the key-custody nodes.
+pub struct Relayer {
+    backends: Vec<Arc<dyn SigningBackend>>,
+    /// Cache of pubkey -> owning backend (by index into `backends`), filled
+    /// in as lookups resolve so repeat requests skip the `has_*` probe.
+    pubkey_cache: HashMap<Vec<u8>, usize>,
+}
+
+impl Relayer {
+    pub fn new(backends: Vec<Arc<dyn SigningBackend>>) -> Self {
+        Self { backends, pubkey_cache: HashMap::new() }
+    }
+
+    /// Resolve the backend that owns `pubkey`, probing each one with
+    /// `owns` until one claims it. The cache is only ever populated from a
+    /// real positive answer, so a miss always falls through to a fresh
+    /// probe rather than silently defaulting to some backend.
+    async fn find_backend<'a, F, Fut>(
+        &'a mut self,
+        pubkey: &[u8],
+        owns: F,
+    ) -> Option<&'a Arc<dyn SigningBackend>>
+    where
+        F: Fn(Arc<dyn SigningBackend>) -> Fut,
+        Fut: std::future::Future<Output = bool>,
+    {
+        if let Some(&index) = self.pubkey_cache.get(pubkey) {
+            return self.backends.get(index);
+        }
+
+        for (index, backend) in self.backends.iter().enumerate() {
+            if owns(backend.clone()).await {
+                self.pubkey_cache.insert(pubkey.to_vec(), index);
+                return self.backends.get(index);
+            }
+        }
+
+        None
+    }
+
+    pub async fn sign_consensus(
+        &mut self,
+        pubkey: &BlsPublicKey,
+        object_root: &[u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError> {
+        let backend = self
+            .find_backend(pubkey.as_ref(), |backend| async move {
+                backend.has_consensus(pubkey).await
+            })
+            .await
+            .ok_or(SignerModuleError::UnknownConsensusSigner(pubkey.to_vec()))?;
+
+        backend.sign_consensus(pubkey, object_root).await
+    }
+
+    pub async fn sign_proxy(
+        &mut self,
+        pubkey: &[u8],
+        object_root: &[u8; 32],
+    ) -> Result<Vec<u8>, SignerModuleError> {
+        let backend = self
+            .find_backend(
+                pubkey,
+                |backend| async move { backend.has_proxy(pubkey).await },
+            )
+            .await
+            .ok_or(SignerModuleError::UnknownProxySigner(pubkey.to_vec()))?;
+
+        backend.sign_proxy(pubkey, object_root).await
+    }
+
+    pub async fn get_delegation(
+        &mut self,
+        pubkey: &[u8],
+    ) -> Result<SignedProxyDelegation, SignerModuleError> {
+        let backend = self
+            .find_backend(
+                pubkey,
+                |backend| async move { backend.has_proxy(pubkey).await },
+            )
+            .await
+            .ok_or(SignerModuleError::UnknownProxySigner(pubkey.to_vec()))?;
+
+        backend.get_delegation(pubkey).await
+    }
+}
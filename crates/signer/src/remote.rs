@@ -0,0 +1,86 @@
+use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
+use cb_common::types::Chain;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::error::SignerModuleError;
+
+/// A consensus signer whose secret key is held by an external process
+/// rather than in-process. `SigningManager` talks to it over
+/// `/api/v1/commit-boost/sign/{pubkey}`, a Commit-Boost-specific JSON API
+/// that signs an already-computed root directly.
+///
+/// This is deliberately **not** the standard Web3Signer `/api/v1/eth2/sign`
+/// contract: stock Web3Signer only accepts full typed beacon-chain messages
+/// (`BLOCK`, `ATTESTATION`, ...) and recomputes the signing root itself from
+/// fork/domain info, whereas Commit-Boost only ever has the pre-hashed root
+/// available by the time a module asks it to sign. Speaking the real
+/// Web3Signer contract would mean threading the original typed message (and
+/// fork schedule) through every caller instead of just a root, which is out
+/// of scope here; the remote daemon this talks to has to implement this
+/// root-signing endpoint itself; it is not interchangeable with an
+/// unmodified Web3Signer deployment.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    pubkey: BlsPublicKey,
+    url: Url,
+    http: reqwest::Client,
+}
+
+/// Request body for `/api/v1/commit-boost/sign/{pubkey}`: just the root to
+/// sign, since the remote daemon isn't expected to recompute it.
+#[derive(Serialize)]
+struct CommitBoostSignRequest {
+    signing_root: String,
+}
+
+#[derive(Deserialize)]
+struct CommitBoostSignResponse {
+    signature: String,
+}
+
+impl RemoteSigner {
+    pub fn new(pubkey: BlsPublicKey, url: Url) -> Self {
+        Self { pubkey, url, http: reqwest::Client::new() }
+    }
+
+    pub fn pubkey(&self) -> BlsPublicKey {
+        self.pubkey
+    }
+
+    /// `chain` isn't sent anywhere: unlike stock Web3Signer, the remote
+    /// daemon isn't recomputing the signing root from fork/domain info, so
+    /// it has no use for it. It's still taken here to match the shape of
+    /// every other `ConsensusSignerBackend` variant's `sign`.
+    pub async fn sign(
+        &self,
+        _chain: Chain,
+        object_root: [u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError> {
+        let endpoint = self
+            .url
+            .join(&format!("api/v1/commit-boost/sign/{}", self.pubkey))
+            .map_err(|err| SignerModuleError::RemoteSigner(err.to_string()))?;
+
+        let body = CommitBoostSignRequest { signing_root: hex::encode_prefixed(object_root) };
+
+        let response = self
+            .http
+            .post(endpoint)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|err| SignerModuleError::RemoteSigner(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SignerModuleError::RemoteSigner(err.to_string()))?
+            .json::<CommitBoostSignResponse>()
+            .await
+            .map_err(|err| SignerModuleError::RemoteSigner(err.to_string()))?;
+
+        let sig_bytes = hex::decode(response.signature.trim_start_matches("0x"))
+            .map_err(|err| SignerModuleError::RemoteSigner(err.to_string()))?;
+
+        BlsSignature::try_from(sig_bytes.as_slice())
+            .map_err(|_| SignerModuleError::RemoteSigner("invalid signature length".to_string()))
+    }
+}
@@ -0,0 +1,159 @@
+use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
+use cb_common::commit::request::SignedProxyDelegation;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{backend::SigningBackend, error::SignerModuleError};
+
+/// An HTTP client for a backend signer node's internal relay API, used by
+/// [`crate::relayer::Relayer`] to forward requests to whichever node
+/// actually owns the requested pubkey. Holds no key material itself.
+pub struct RemoteSigningManager {
+    base_url: Url,
+    http: reqwest::Client,
+}
+
+#[derive(Serialize)]
+struct SignRequest<'a> {
+    pubkey: &'a [u8],
+    object_root: [u8; 32],
+}
+
+#[derive(Deserialize)]
+struct ConsensusSignResponse {
+    signature: BlsSignature,
+}
+
+#[derive(Deserialize)]
+struct ProxySignResponse {
+    signature: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct OwnershipResponse {
+    owns: bool,
+}
+
+impl RemoteSigningManager {
+    pub fn new(base_url: Url) -> Self {
+        Self {
+            base_url,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    fn endpoint(&self, path: &str) -> Result<Url, SignerModuleError> {
+        self.base_url
+            .join(path)
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))
+    }
+
+    async fn post<Req: Serialize, Res: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: &Req,
+    ) -> Result<Res, SignerModuleError> {
+        self.http
+            .post(self.endpoint(path)?)
+            .json(body)
+            .send()
+            .await
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))?
+            .json::<Res>()
+            .await
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))
+    }
+
+    /// Ask the remote node whether it owns `pubkey`, used by
+    /// [`crate::relayer::Relayer`] to pick which backend a request belongs
+    /// to. Any failure (network error, non-2xx, bad body) is treated as "no"
+    /// rather than propagated, since a relayer probing several backends
+    /// shouldn't fail the whole lookup because one of them is unreachable.
+    async fn owns(&self, kind: &str, pubkey: &[u8]) -> bool {
+        let Ok(url) = self.endpoint(&format!("relay/v1/owns/{kind}/{}", hex::encode(pubkey)))
+        else {
+            return false;
+        };
+
+        let Ok(response) = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+        else {
+            return false;
+        };
+
+        response
+            .json::<OwnershipResponse>()
+            .await
+            .map(|body| body.owns)
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait::async_trait]
+impl SigningBackend for RemoteSigningManager {
+    async fn sign_consensus(
+        &self,
+        pubkey: &BlsPublicKey,
+        object_root: &[u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError> {
+        let response: ConsensusSignResponse = self
+            .post(
+                "relay/v1/sign_consensus",
+                &SignRequest {
+                    pubkey: pubkey.as_ref(),
+                    object_root: *object_root,
+                },
+            )
+            .await?;
+
+        Ok(response.signature)
+    }
+
+    async fn sign_proxy(
+        &self,
+        pubkey: &[u8],
+        object_root: &[u8; 32],
+    ) -> Result<Vec<u8>, SignerModuleError> {
+        let response: ProxySignResponse = self
+            .post(
+                "relay/v1/sign_proxy",
+                &SignRequest {
+                    pubkey,
+                    object_root: *object_root,
+                },
+            )
+            .await?;
+
+        Ok(response.signature)
+    }
+
+    async fn get_delegation(
+        &self,
+        pubkey: &[u8],
+    ) -> Result<SignedProxyDelegation, SignerModuleError> {
+        self.http
+            .get(self.endpoint(&format!("relay/v1/delegation/{}", hex::encode(pubkey)))?)
+            .send()
+            .await
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))?
+            .json::<SignedProxyDelegation>()
+            .await
+            .map_err(|err| SignerModuleError::Relay(err.to_string()))
+    }
+
+    async fn has_consensus(&self, pubkey: &BlsPublicKey) -> bool {
+        self.owns("consensus", pubkey.as_ref()).await
+    }
+
+    async fn has_proxy(&self, pubkey: &[u8]) -> bool {
+        self.owns("proxy", pubkey).await
+    }
+}
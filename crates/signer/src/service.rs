@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use cb_common::{commit::request::SignedProxyDelegation, config::StartSignerConfig};
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use tokio::{net::TcpListener, sync::Mutex};
+use tracing::info;
+
+use crate::{
+    backend::SigningBackend,
+    config::{ExternalConsensusSignersConfig, StartRelayerConfig},
+    error::SignerModuleError,
+    manager::{ProxyBatchSignature, SigningManager},
+    relayer::Relayer,
+    remote_manager::RemoteSigningManager,
+    threshold::{PartialSignRequest, PartialSignResponse},
+};
+
+/// Entrypoint for the two ways this module gets deployed: [`Self::run`]
+/// starts a key-custody node backed by a [`SigningManager`], [`Self::run_relayer`]
+/// starts a non-signing node that forwards every request to whichever
+/// configured backend owns the pubkey (see [`crate::relayer::Relayer`]).
+/// Both expose `relay/v1/sign_consensus`, `relay/v1/sign_proxy` and
+/// `relay/v1/delegation/*`. Only [`Self::run`] answers the `relay/v1/owns/*`
+/// probe a relayer sends its backends, so a relayer cannot be used as
+/// another relayer's backend. [`Self::run`] additionally exposes
+/// `relay/v1/sign_proxy_batch`, which aggregates across the whole batch and
+/// so doesn't generalize to a relayer that may front more than one backend.
+pub struct SigningService;
+
+impl SigningService {
+    /// Start a key-custody node: builds a [`SigningManager`] from `config`,
+    /// registers any remote consensus signers found in
+    /// [`ExternalConsensusSignersConfig`], and serves requests off of it.
+    pub async fn run(config: StartSignerConfig) -> Result<()> {
+        let mut manager = SigningManager::new(config.chain, config.keystore_config)
+            .wrap_err("failed to initialize signing manager")?;
+
+        let external_signers = ExternalConsensusSignersConfig::load_from_env()
+            .wrap_err("failed to load external consensus signer config")?;
+        for remote in external_signers.remote {
+            manager.add_remote_consensus_signer(remote.pubkey, remote.url);
+        }
+        for threshold in external_signers.threshold {
+            let share = threshold.share().wrap_err("failed to load threshold share")?;
+            manager.add_threshold_consensus_signer(
+                threshold.pubkey,
+                share,
+                threshold.threshold,
+                threshold.peers,
+            );
+        }
+
+        let manager = Arc::new(manager);
+        let backend: Arc<dyn SigningBackend> = manager.clone();
+
+        Self::serve(router(backend).merge(manager_router(manager)), config.server_address).await
+    }
+
+    /// Start a relayer node: connects a [`RemoteSigningManager`] to each
+    /// configured backend and serves the same API surface as [`Self::run`],
+    /// forwarding every request to whichever backend owns the pubkey.
+    pub async fn run_relayer(config: StartRelayerConfig) -> Result<()> {
+        let backends: Vec<Arc<dyn SigningBackend>> = config
+            .backend_urls
+            .into_iter()
+            .map(|url| Arc::new(RemoteSigningManager::new(url)) as Arc<dyn SigningBackend>)
+            .collect();
+        let relayer = Arc::new(Mutex::new(Relayer::new(backends)));
+
+        Self::serve(relayer_router(relayer), config.server_address).await
+    }
+
+    async fn serve(app: Router, address: std::net::SocketAddr) -> Result<()> {
+        info!(%address, "starting signer module");
+        let listener = TcpListener::bind(address)
+            .await
+            .wrap_err("failed to bind signer address")?;
+        axum::serve(listener, app)
+            .await
+            .wrap_err("signer server exited")
+    }
+}
+
+#[derive(Deserialize)]
+struct SignRequest {
+    pubkey: Vec<u8>,
+    object_root: [u8; 32],
+}
+
+#[derive(Serialize)]
+struct ConsensusSignResponse {
+    signature: BlsSignature,
+}
+
+#[derive(Serialize)]
+struct ProxySignResponse {
+    signature: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct OwnershipResponse {
+    owns: bool,
+}
+
+impl IntoResponse for SignerModuleError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            SignerModuleError::UnknownConsensusSigner(_)
+            | SignerModuleError::UnknownProxySigner(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// Routes backed directly by a [`SigningBackend`] (a local [`SigningManager`]).
+/// Includes `relay/v1/owns/*`, which is what [`RemoteSigningManager`] probes
+/// to resolve ownership on behalf of a relayer pointed at this node.
+fn router(backend: Arc<dyn SigningBackend>) -> Router {
+    Router::new()
+        .route("/relay/v1/sign_consensus", post(sign_consensus))
+        .route("/relay/v1/sign_proxy", post(sign_proxy))
+        .route("/relay/v1/delegation/:pubkey", get(get_delegation))
+        .route("/relay/v1/owns/:kind/:pubkey", get(owns))
+        .with_state(backend)
+}
+
+async fn owns(
+    State(backend): State<Arc<dyn SigningBackend>>,
+    Path((kind, pubkey)): Path<(String, String)>,
+) -> Result<Json<OwnershipResponse>, SignerModuleError> {
+    let pubkey_bytes = hex::decode(pubkey.trim_start_matches("0x"))
+        .map_err(|_| SignerModuleError::UnknownProxySigner(vec![]))?;
+
+    let owns = match kind.as_str() {
+        "consensus" => match BlsPublicKey::try_from(pubkey_bytes.as_slice()) {
+            Ok(pubkey) => backend.has_consensus(&pubkey).await,
+            Err(_) => false,
+        },
+        _ => backend.has_proxy(&pubkey_bytes).await,
+    };
+
+    Ok(Json(OwnershipResponse { owns }))
+}
+
+async fn sign_consensus(
+    State(backend): State<Arc<dyn SigningBackend>>,
+    Json(req): Json<SignRequest>,
+) -> Result<Json<ConsensusSignResponse>, SignerModuleError> {
+    let pubkey = BlsPublicKey::try_from(req.pubkey.as_slice())
+        .map_err(|_| SignerModuleError::UnknownConsensusSigner(req.pubkey.clone()))?;
+    let signature = backend.sign_consensus(&pubkey, &req.object_root).await?;
+
+    Ok(Json(ConsensusSignResponse { signature }))
+}
+
+async fn sign_proxy(
+    State(backend): State<Arc<dyn SigningBackend>>,
+    Json(req): Json<SignRequest>,
+) -> Result<Json<ProxySignResponse>, SignerModuleError> {
+    let signature = backend.sign_proxy(&req.pubkey, &req.object_root).await?;
+
+    Ok(Json(ProxySignResponse { signature }))
+}
+
+async fn get_delegation(
+    State(backend): State<Arc<dyn SigningBackend>>,
+    Path(pubkey): Path<String>,
+) -> Result<Json<SignedProxyDelegation>, SignerModuleError> {
+    let pubkey = hex::decode(pubkey.trim_start_matches("0x"))
+        .map_err(|_| SignerModuleError::UnknownProxySigner(vec![]))?;
+    let delegation = backend.get_delegation(&pubkey).await?;
+
+    Ok(Json(delegation))
+}
+
+#[derive(Deserialize)]
+struct SignBatchRequest {
+    requests: Vec<(Vec<u8>, [u8; 32])>,
+}
+
+#[derive(Serialize)]
+struct ProxyBatchSignResponse {
+    bls_aggregate: Option<(BlsSignature, Vec<Vec<u8>>)>,
+    ecdsa: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+/// Routes that need the concrete [`SigningManager`] rather than the
+/// [`SigningBackend`] trait object [`router`] uses: batch proxy signing,
+/// which aggregates BLS signatures across the whole batch, and the
+/// threshold peer endpoint, which exposes this node's own Shamir share.
+/// Neither has a sensible `SigningBackend`-level generalization to a
+/// relayer.
+fn manager_router(manager: Arc<SigningManager>) -> Router {
+    Router::new()
+        .route("/relay/v1/sign_proxy_batch", post(sign_proxy_batch))
+        .route("/api/v1/threshold/sign/:pubkey", post(sign_threshold_partial))
+        .with_state(manager)
+}
+
+/// Peer endpoint `ThresholdSigner::request_partial` calls to collect this
+/// node's partial signature over a threshold-shared consensus key.
+async fn sign_threshold_partial(
+    State(manager): State<Arc<SigningManager>>,
+    Path(pubkey): Path<String>,
+    Json(req): Json<PartialSignRequest>,
+) -> Result<Json<PartialSignResponse>, SignerModuleError> {
+    let pubkey_bytes = hex::decode(pubkey.trim_start_matches("0x"))
+        .map_err(|_| SignerModuleError::UnknownConsensusSigner(vec![]))?;
+    let pubkey = BlsPublicKey::try_from(pubkey_bytes.as_slice())
+        .map_err(|_| SignerModuleError::UnknownConsensusSigner(pubkey_bytes.clone()))?;
+    let object_root_bytes = hex::decode(req.object_root.trim_start_matches("0x"))
+        .map_err(|_| SignerModuleError::UnknownConsensusSigner(pubkey.to_vec()))?;
+    let object_root: [u8; 32] = object_root_bytes
+        .try_into()
+        .map_err(|_| SignerModuleError::UnknownConsensusSigner(pubkey.to_vec()))?;
+
+    let response = manager.sign_threshold_partial(&pubkey, &object_root).await?;
+
+    Ok(Json(response))
+}
+
+async fn sign_proxy_batch(
+    State(manager): State<Arc<SigningManager>>,
+    Json(req): Json<SignBatchRequest>,
+) -> Result<Json<ProxyBatchSignResponse>, SignerModuleError> {
+    let ProxyBatchSignature { bls_aggregate, ecdsa } =
+        manager.sign_proxy_batch(&req.requests).await?;
+
+    let bls_aggregate = bls_aggregate.map(|(signature, signers)| {
+        (signature, signers.into_iter().map(|pk| pk.as_ref().to_vec()).collect())
+    });
+    let ecdsa = ecdsa.into_iter().map(|(pk, sig)| (pk.as_ref().to_vec(), sig)).collect();
+
+    Ok(Json(ProxyBatchSignResponse { bls_aggregate, ecdsa }))
+}
+
+/// Routes backed by a [`Relayer`], which needs `&mut self` to populate its
+/// pubkey cache and so is shared behind a mutex rather than handed out as a
+/// trait object. Mirrors [`router`] one-for-one (minus `relay/v1/owns/*`,
+/// which only a key-custody node answers), so a relayer answers those routes
+/// the way the node it fronts does; the [`manager_router`] routes
+/// (batch proxy signing and the threshold peer endpoint) are not served.
+fn relayer_router(relayer: Arc<Mutex<Relayer>>) -> Router {
+    Router::new()
+        .route("/relay/v1/sign_consensus", post(relayer_sign_consensus))
+        .route("/relay/v1/sign_proxy", post(relayer_sign_proxy))
+        .route("/relay/v1/delegation/:pubkey", get(relayer_get_delegation))
+        .with_state(relayer)
+}
+
+async fn relayer_sign_consensus(
+    State(relayer): State<Arc<Mutex<Relayer>>>,
+    Json(req): Json<SignRequest>,
+) -> Result<Json<ConsensusSignResponse>, SignerModuleError> {
+    let pubkey = BlsPublicKey::try_from(req.pubkey.as_slice())
+        .map_err(|_| SignerModuleError::UnknownConsensusSigner(req.pubkey.clone()))?;
+    let signature = relayer
+        .lock()
+        .await
+        .sign_consensus(&pubkey, &req.object_root)
+        .await?;
+
+    Ok(Json(ConsensusSignResponse { signature }))
+}
+
+async fn relayer_sign_proxy(
+    State(relayer): State<Arc<Mutex<Relayer>>>,
+    Json(req): Json<SignRequest>,
+) -> Result<Json<ProxySignResponse>, SignerModuleError> {
+    let signature = relayer
+        .lock()
+        .await
+        .sign_proxy(&req.pubkey, &req.object_root)
+        .await?;
+
+    Ok(Json(ProxySignResponse { signature }))
+}
+
+async fn relayer_get_delegation(
+    State(relayer): State<Arc<Mutex<Relayer>>>,
+    Path(pubkey): Path<String>,
+) -> Result<Json<SignedProxyDelegation>, SignerModuleError> {
+    let pubkey = hex::decode(pubkey.trim_start_matches("0x"))
+        .map_err(|_| SignerModuleError::UnknownProxySigner(vec![]))?;
+    let delegation = relayer.lock().await.get_delegation(&pubkey).await?;
+
+    Ok(Json(delegation))
+}
@@ -0,0 +1,383 @@
+use alloy::rpc::types::beacon::{BlsPublicKey, BlsSignature};
+use blst::{
+    blst_bendian_from_scalar, blst_fr, blst_fr_from_scalar, blst_fr_inverse, blst_fr_mul,
+    blst_fr_sub, blst_p2, blst_p2_add_or_double, blst_p2_affine, blst_p2_from_affine, blst_p2_mult,
+    blst_p2_to_affine, blst_scalar, blst_scalar_from_fr, blst_scalar_from_uint64,
+    min_pk::{SecretKey as BlsSecretKey, Signature as BlsSigPoint},
+};
+use cb_common::{signer::Signer, types::Chain};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::error::SignerModuleError;
+
+/// Domain separation tag for the BLS ciphersuite consensus messages in this
+/// crate are signed under (the standard Ethereum consensus ciphersuite).
+/// Shared with the split/combine test below and with `manager.rs`'s
+/// `sign_proxy_batch` test, since both verify signatures produced the same
+/// way `sign_partial`/`ProxySigner::sign` produce them.
+pub(crate) const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// This node's Shamir share of a consensus BLS secret key: `index` is the
+/// share's x-coordinate in the sharing polynomial (1-indexed, never 0 since
+/// `f(0)` is the real secret) and `secret` is `f(index)`.
+#[derive(Clone)]
+pub struct ThresholdShare {
+    pub index: u64,
+    pub secret: BlsSecretKey,
+}
+
+/// A consensus signer whose key is split `t`-of-`n` across peer signer nodes
+/// via Shamir secret sharing, so no single node ever holds the full secret.
+/// `sign` collects partial BLS signatures from enough peers over the same
+/// `object_root` and combines them via Lagrange interpolation at `x = 0`.
+#[derive(Clone)]
+pub struct ThresholdSigner {
+    pubkey: BlsPublicKey,
+    share: ThresholdShare,
+    threshold: usize,
+    peers: Vec<Url>,
+    http: reqwest::Client,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PartialSignRequest {
+    pub(crate) object_root: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct PartialSignResponse {
+    pub(crate) index: u64,
+    pub(crate) signature: String,
+}
+
+impl ThresholdSigner {
+    pub fn new(
+        pubkey: BlsPublicKey,
+        share: ThresholdShare,
+        threshold: usize,
+        peers: Vec<Url>,
+    ) -> Self {
+        Self { pubkey, share, threshold, peers, http: reqwest::Client::new() }
+    }
+
+    pub fn pubkey(&self) -> BlsPublicKey {
+        self.pubkey
+    }
+
+    pub async fn sign(
+        &self,
+        chain: Chain,
+        object_root: [u8; 32],
+    ) -> Result<BlsSignature, SignerModuleError> {
+        let mut partials = vec![(self.share.index, self.sign_partial(chain, object_root).await?)];
+
+        for peer in &self.peers {
+            if partials.len() >= self.threshold {
+                break;
+            }
+            if let Ok(partial) = self.request_partial(peer, object_root).await {
+                partials.push(partial);
+            }
+        }
+
+        if partials.len() < self.threshold {
+            return Err(SignerModuleError::ThresholdSigner(format!(
+                "only collected {} of {} required partial signatures",
+                partials.len(),
+                self.threshold
+            )));
+        }
+
+        let combined = combine_partials(&partials)?;
+        Ok(BlsSignature::from_slice(&combined.to_bytes()))
+    }
+
+    /// A Shamir share is itself a valid BLS scalar, so this node's partial
+    /// signature is just an ordinary signature made with the share as the
+    /// secret key: `partial_i = share_i * H(m)`.
+    async fn sign_partial(
+        &self,
+        chain: Chain,
+        object_root: [u8; 32],
+    ) -> Result<BlsSigPoint, SignerModuleError> {
+        let signer = Signer::new_from_secret(self.share.secret.clone());
+        let signature = signer.sign(chain, object_root).await;
+
+        BlsSigPoint::from_bytes(signature.as_ref())
+            .map_err(|err| SignerModuleError::ThresholdSigner(format!("{err:?}")))
+    }
+
+    /// Server-side counterpart to [`Self::request_partial`]: produces this
+    /// node's partial signature over `object_root` in the wire format a peer
+    /// expects back from `/api/v1/threshold/sign/{pubkey}`.
+    pub(crate) async fn sign_partial_response(
+        &self,
+        chain: Chain,
+        object_root: [u8; 32],
+    ) -> Result<PartialSignResponse, SignerModuleError> {
+        let partial = self.sign_partial(chain, object_root).await?;
+        Ok(PartialSignResponse {
+            index: self.share.index,
+            signature: hex::encode_prefixed(partial.to_bytes()),
+        })
+    }
+
+    async fn request_partial(
+        &self,
+        peer: &Url,
+        object_root: [u8; 32],
+    ) -> Result<(u64, BlsSigPoint), SignerModuleError> {
+        let endpoint = peer
+            .join(&format!("api/v1/threshold/sign/{}", self.pubkey))
+            .map_err(|err| SignerModuleError::ThresholdSigner(err.to_string()))?;
+
+        let response = self
+            .http
+            .post(endpoint)
+            .json(&PartialSignRequest { object_root: hex::encode_prefixed(object_root) })
+            .send()
+            .await
+            .map_err(|err| SignerModuleError::ThresholdSigner(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| SignerModuleError::ThresholdSigner(err.to_string()))?
+            .json::<PartialSignResponse>()
+            .await
+            .map_err(|err| SignerModuleError::ThresholdSigner(err.to_string()))?;
+
+        let sig_bytes = hex::decode(response.signature.trim_start_matches("0x"))
+            .map_err(|err| SignerModuleError::ThresholdSigner(err.to_string()))?;
+        let sig_point = BlsSigPoint::from_bytes(&sig_bytes)
+            .map_err(|err| SignerModuleError::ThresholdSigner(format!("{err:?}")))?;
+
+        Ok((response.index, sig_point))
+    }
+}
+
+/// Shamir-split an existing consensus secret key into `n` shares, any
+/// `threshold` of which can later reconstruct a signature. Used to
+/// provision a fleet of signer nodes from a single imported key; a DKG round
+/// is the non-custodial alternative but isn't implemented here.
+pub fn split_secret(
+    secret: &BlsSecretKey,
+    threshold: usize,
+    shares: usize,
+) -> Result<Vec<ThresholdShare>, SignerModuleError> {
+    if threshold == 0 || threshold > shares {
+        return Err(SignerModuleError::ThresholdSigner(format!(
+            "invalid threshold {threshold} for {shares} shares"
+        )));
+    }
+
+    // Random polynomial f(x) = secret + c_1*x + ... + c_{threshold-1}*x^{threshold-1}.
+    let mut coefficients = vec![fr_from_secret(secret)];
+    for _ in 1..threshold {
+        coefficients.push(fr_from_secret(&BlsSecretKey::key_gen(&rand_ikm(), &[]).map_err(
+            |err| SignerModuleError::ThresholdSigner(format!("{err:?}")),
+        )?));
+    }
+
+    (1..=shares as u64)
+        .map(|index| {
+            let x = fr_from_u64(index);
+            let mut acc = fr_zero();
+            for coefficient in coefficients.iter().rev() {
+                acc = fr_mul(&acc, &x);
+                acc = fr_add(&acc, coefficient);
+            }
+            let secret = BlsSecretKey::from_bytes(&fr_to_bendian(&acc))
+                .map_err(|err| SignerModuleError::ThresholdSigner(format!("{err:?}")))?;
+            Ok(ThresholdShare { index, secret })
+        })
+        .collect()
+}
+
+fn rand_ikm() -> [u8; 32] {
+    use rand::RngCore;
+    let mut ikm = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut ikm);
+    ikm
+}
+
+/// Combine `t` partial signatures into the final group signature via
+/// Lagrange interpolation at `x = 0`: `signature = sum(lambda_i * partial_i)`
+/// where `lambda_i = prod_{j != i}(x_j / (x_j - x_i))` are the Lagrange
+/// coefficients for the participating share indices.
+fn combine_partials(partials: &[(u64, BlsSigPoint)]) -> Result<BlsSigPoint, SignerModuleError> {
+    let indices: Vec<u64> = partials.iter().map(|(index, _)| *index).collect();
+
+    let mut acc = blst_p2::default();
+    let mut acc_set = false;
+
+    for (index, partial) in partials {
+        let lambda = lagrange_coefficient(*index, &indices);
+        let scaled = scalar_mul_p2(partial, &lambda);
+
+        if acc_set {
+            unsafe { blst_p2_add_or_double(&mut acc, &acc, &scaled) };
+        } else {
+            acc = scaled;
+            acc_set = true;
+        }
+    }
+
+    let mut affine = blst_p2_affine::default();
+    unsafe { blst_p2_to_affine(&mut affine, &acc) };
+
+    BlsSigPoint::from_bytes(&p2_affine_compress(&affine))
+        .map_err(|err| SignerModuleError::ThresholdSigner(format!("{err:?}")))
+}
+
+fn lagrange_coefficient(index: u64, all_indices: &[u64]) -> blst_fr {
+    let xi = fr_from_u64(index);
+    let mut num = fr_one();
+    let mut den = fr_one();
+
+    for &j in all_indices {
+        if j == index {
+            continue;
+        }
+        let xj = fr_from_u64(j);
+        num = fr_mul(&num, &xj);
+        den = fr_mul(&den, &fr_sub(&xj, &xi));
+    }
+
+    fr_mul(&num, &fr_inverse(&den))
+}
+
+fn scalar_mul_p2(point: &BlsSigPoint, scalar: &blst_fr) -> blst_p2 {
+    let mut affine = blst_p2_affine::default();
+    let sig_bytes = point.to_bytes();
+    unsafe {
+        blst::blst_p2_uncompress(&mut affine, sig_bytes.as_ptr());
+    }
+
+    let mut projective = blst_p2::default();
+    unsafe { blst_p2_from_affine(&mut projective, &affine) };
+
+    let scalar = fr_to_scalar(scalar);
+    let mut out = blst_p2::default();
+    unsafe { blst_p2_mult(&mut out, &projective, scalar.b.as_ptr(), 255) };
+
+    out
+}
+
+fn p2_affine_compress(affine: &blst_p2_affine) -> [u8; 96] {
+    let mut out = [0u8; 96];
+    unsafe { blst::blst_p2_affine_compress(out.as_mut_ptr(), affine) };
+    out
+}
+
+fn fr_zero() -> blst_fr {
+    fr_from_u64(0)
+}
+
+fn fr_one() -> blst_fr {
+    fr_from_u64(1)
+}
+
+fn fr_from_u64(value: u64) -> blst_fr {
+    let mut scalar = blst_scalar::default();
+    unsafe { blst_scalar_from_uint64(&mut scalar, [value, 0, 0, 0].as_ptr()) };
+    let mut fr = blst_fr::default();
+    unsafe { blst_fr_from_scalar(&mut fr, &scalar) };
+    fr
+}
+
+fn fr_from_secret(secret: &BlsSecretKey) -> blst_fr {
+    let mut scalar = blst_scalar::default();
+    let bytes = secret.to_bytes();
+    unsafe { blst::blst_scalar_from_bendian(&mut scalar, bytes.as_ptr()) };
+    let mut fr = blst_fr::default();
+    unsafe { blst_fr_from_scalar(&mut fr, &scalar) };
+    fr
+}
+
+fn fr_to_scalar(fr: &blst_fr) -> blst_scalar {
+    let mut scalar = blst_scalar::default();
+    unsafe { blst_scalar_from_fr(&mut scalar, fr) };
+    scalar
+}
+
+/// Big-endian encoding of `fr`, suitable for `BlsSecretKey::from_bytes`
+/// (which, like `to_bytes`, is big-endian). `blst_scalar`'s own `.b` field is
+/// little-endian internally, so that must not be fed to `from_bytes`
+/// directly (see `fr_from_secret`, which does the equivalent inverse
+/// conversion via `blst_scalar_from_bendian`).
+fn fr_to_bendian(fr: &blst_fr) -> [u8; 32] {
+    let scalar = fr_to_scalar(fr);
+    let mut bytes = [0u8; 32];
+    unsafe { blst_bendian_from_scalar(bytes.as_mut_ptr(), &scalar) };
+    bytes
+}
+
+fn fr_mul(a: &blst_fr, b: &blst_fr) -> blst_fr {
+    let mut out = blst_fr::default();
+    unsafe { blst_fr_mul(&mut out, a, b) };
+    out
+}
+
+fn fr_add(a: &blst_fr, b: &blst_fr) -> blst_fr {
+    // Computed as `a - (0 - b)`, so addition needs no primitive beyond the
+    // `blst_fr_sub` that the Lagrange coefficients above already use.
+    fr_sub(a, &fr_sub(&fr_zero(), b))
+}
+
+fn fr_sub(a: &blst_fr, b: &blst_fr) -> blst_fr {
+    let mut out = blst_fr::default();
+    unsafe { blst_fr_sub(&mut out, a, b) };
+    out
+}
+
+fn fr_inverse(a: &blst_fr) -> blst_fr {
+    let mut out = blst_fr::default();
+    unsafe { blst_fr_inverse(&mut out, a) };
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use cb_common::signature::compute_signing_root;
+    use tree_hash::{Hash256, TreeHash};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_split_and_combine_partials_verifies() {
+        let secret =
+            BlsSecretKey::key_gen(b"threshold test ikm, at least 32 bytes long!!", &[]).unwrap();
+        let pubkey_point = secret.sk_to_pk();
+        let pubkey = BlsPublicKey::try_from(pubkey_point.to_bytes().as_slice()).unwrap();
+
+        let shares = split_secret(&secret, 3, 5).unwrap();
+        let chain = Chain::Holesky;
+
+        let signers: Vec<ThresholdSigner> = shares
+            .into_iter()
+            .take(3)
+            .map(|share| ThresholdSigner::new(pubkey, share, 3, vec![]))
+            .collect();
+
+        let data_root = Hash256::random();
+        let object_root = *data_root.as_fixed_bytes();
+
+        let mut partials = Vec::new();
+        for signer in &signers {
+            let response = signer.sign_partial_response(chain, object_root).await.unwrap();
+            let sig_bytes = hex::decode(response.signature.trim_start_matches("0x")).unwrap();
+            partials.push((response.index, BlsSigPoint::from_bytes(&sig_bytes).unwrap()));
+        }
+
+        let combined = combine_partials(&partials).unwrap();
+
+        let domain = chain.builder_domain();
+        let signing_root = compute_signing_root(object_root.tree_hash_root().0, domain);
+        let result = combined.verify(true, signing_root.as_ref(), BLS_DST, &[], &pubkey_point, true);
+
+        assert_eq!(
+            result,
+            blst::BLST_ERROR::BLST_SUCCESS,
+            "signature combined from threshold partials must verify against the original pubkey"
+        );
+    }
+}